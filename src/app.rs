@@ -0,0 +1,402 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use axum::extract::{Form, Path, Query, State};
+use axum::http::StatusCode;
+use axum::response::Redirect;
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use chrono::{NaiveDate, Utc};
+use serde::Deserialize;
+use tower_http::trace::{self, TraceLayer};
+use tracing::Level;
+use utoipa::OpenApi;
+use utoipa_rapidoc::RapiDoc;
+
+use crate::chat::{ChatPlatform, ChatResponse};
+use crate::lunch;
+use crate::lunch::{Building, LunchProvider};
+use crate::mattermost::{MattermostCommandResponse, MattermostSlashCommandRequest};
+use crate::provider::{IssCateringProvider, DEFAULT_CACHE_STALENESS_WINDOW};
+use crate::Markdown;
+
+const DEFAULT_CATERING_BASE_URL: &str = "https://lego.isscatering.dk";
+const USAGE: &str = "Usage: `/lunch <building>`, e.g. `/lunch aastvej`";
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        get_lunch,
+        get_lunch_week,
+        get_lunch_for_platform,
+        get_lunch_week_for_platform,
+    ),
+    components(
+        schemas(lunch::Building, crate::chat::ChatPlatform)
+    ),
+    tags(
+        (name = "lunch", description = "Lunch")
+    )
+)]
+struct ApiDoc;
+
+/// Shared application state: the lunch provider (which owns the HTTP client
+/// and catering base URL) and the Mattermost token the handlers depend on.
+/// Injecting the provider here (rather than reaching for `reqwest::get` and a
+/// hardcoded URL in the handlers) is what lets tests point a router at a
+/// local fixture server instead of the real site.
+#[derive(Clone)]
+pub(crate) struct AppState {
+    provider: Arc<dyn LunchProvider>,
+    mattermost_token: String,
+}
+
+impl AppState {
+    pub(crate) fn from_cfg(
+        client: reqwest::Client,
+        catering_base_url: String,
+        mattermost_token: String,
+        cache_staleness_window: Duration,
+    ) -> Self {
+        Self {
+            provider: Arc::new(IssCateringProvider::new(
+                client,
+                catering_base_url,
+                cache_staleness_window,
+            )),
+            mattermost_token,
+        }
+    }
+
+    pub(crate) fn from_env() -> Self {
+        let catering_base_url = std::env::var("CATERING_BASE_URL")
+            .unwrap_or_else(|_| DEFAULT_CATERING_BASE_URL.to_owned());
+        let mattermost_token = std::env::var("MATTERMOST_TOKEN").unwrap_or_default();
+        let cache_staleness_window = std::env::var("CACHE_STALENESS_SECONDS")
+            .ok()
+            .and_then(|secs| secs.parse().ok())
+            .map(Duration::from_secs)
+            .unwrap_or(DEFAULT_CACHE_STALENESS_WINDOW);
+
+        if mattermost_token.is_empty() {
+            log::warn!(
+                "MATTERMOST_TOKEN is not set — POST /api/lunch will reject every request until it is configured"
+            );
+        }
+
+        Self::from_cfg(
+            reqwest::Client::new(),
+            catering_base_url,
+            mattermost_token,
+            cache_staleness_window,
+        )
+    }
+
+    #[cfg(test)]
+    fn from_provider(provider: Arc<dyn LunchProvider>, mattermost_token: &str) -> Self {
+        Self {
+            provider,
+            mattermost_token: mattermost_token.to_owned(),
+        }
+    }
+}
+
+pub(crate) struct App;
+
+impl App {
+    pub(crate) fn router(state: AppState) -> Router {
+        let api = Router::new()
+            .route("/:building/lunch", get(get_lunch))
+            .route("/:building/lunch/week", get(get_lunch_week))
+            .route("/lunch", post(post_lunch_command))
+            .route("/:platform/:building/lunch", get(get_lunch_for_platform))
+            .route(
+                "/:platform/:building/lunch/week",
+                get(get_lunch_week_for_platform),
+            );
+
+        Router::new()
+            .merge(RapiDoc::with_openapi("/api-docs/openapi.json", ApiDoc::openapi()).path("/rapidoc"))
+            .route("/", get(|| async { Redirect::permanent("/rapidoc") }))
+            .nest("/api", api)
+            .layer(
+                TraceLayer::new_for_http()
+                    .make_span_with(trace::DefaultMakeSpan::new().level(Level::INFO))
+                    .on_response(trace::DefaultOnResponse::new().level(Level::INFO)),
+            )
+            .with_state(state)
+    }
+}
+
+#[derive(Deserialize)]
+struct LunchQuery {
+    date: Option<NaiveDate>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/{building}/lunch",
+    params(
+        ("building" = Building, Path, description = "the building for which to get lunch"),
+        ("date" = Option<NaiveDate>, Query, description = "the date to get lunch for, defaults to today")
+    ),
+    responses(
+        (status = 200, description = "Get lunch for specified building")
+    )
+)]
+async fn get_lunch(
+    State(state): State<AppState>,
+    Path(building): Path<Building>,
+    Query(query): Query<LunchQuery>,
+) -> Result<Json<MattermostCommandResponse>, StatusCode> {
+    let date = query.date.unwrap_or_else(|| Utc::now().date_naive());
+
+    match lunch::get_lunch(state.provider.as_ref(), building, date).await {
+        Ok(markdown_lunch) => Ok(Json(MattermostCommandResponse::in_channel(markdown_lunch))),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/{building}/lunch/week",
+    params(
+        ("building" = Building, Path, description = "the building for which to get the week's lunch")
+    ),
+    responses(
+        (status = 200, description = "Get the full week's lunch for specified building")
+    )
+)]
+async fn get_lunch_week(
+    State(state): State<AppState>,
+    Path(building): Path<Building>,
+) -> Result<Json<MattermostCommandResponse>, StatusCode> {
+    let today = Utc::now().date_naive();
+
+    match lunch::get_lunch_week(state.provider.as_ref(), building, today).await {
+        Ok(markdown_lunch) => Ok(Json(MattermostCommandResponse::in_channel(markdown_lunch))),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/{platform}/{building}/lunch",
+    params(
+        ("platform" = ChatPlatform, Path, description = "the chat platform to render the response for"),
+        ("building" = Building, Path, description = "the building for which to get lunch"),
+        ("date" = Option<NaiveDate>, Query, description = "the date to get lunch for, defaults to today")
+    ),
+    responses(
+        (status = 200, description = "Get lunch for specified building, rendered for the given chat platform")
+    )
+)]
+async fn get_lunch_for_platform(
+    State(state): State<AppState>,
+    Path((platform, building)): Path<(ChatPlatform, Building)>,
+    Query(query): Query<LunchQuery>,
+) -> ChatResponse {
+    let date = query.date.unwrap_or_else(|| Utc::now().date_naive());
+
+    match lunch::get_lunch(state.provider.as_ref(), building, date).await {
+        Ok(markdown_lunch) => ChatResponse::in_channel(platform, markdown_lunch),
+        Err(_) => ChatResponse::ephemeral(
+            platform,
+            Markdown(format!(
+                "Could not fetch lunch for {}, please try again later.",
+                building
+            )),
+        ),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/{platform}/{building}/lunch/week",
+    params(
+        ("platform" = ChatPlatform, Path, description = "the chat platform to render the response for"),
+        ("building" = Building, Path, description = "the building for which to get the week's lunch")
+    ),
+    responses(
+        (status = 200, description = "Get the full week's lunch for specified building, rendered for the given chat platform")
+    )
+)]
+async fn get_lunch_week_for_platform(
+    State(state): State<AppState>,
+    Path((platform, building)): Path<(ChatPlatform, Building)>,
+) -> ChatResponse {
+    let today = Utc::now().date_naive();
+
+    match lunch::get_lunch_week(state.provider.as_ref(), building, today).await {
+        Ok(markdown_lunch) => ChatResponse::in_channel(platform, markdown_lunch),
+        Err(_) => ChatResponse::ephemeral(
+            platform,
+            Markdown(format!(
+                "Could not fetch this week's lunch for {}, please try again later.",
+                building
+            )),
+        ),
+    }
+}
+
+/// The actual Mattermost slash command endpoint: `/lunch <building>` posted
+/// as url-encoded form data. Responses are always `200 OK` since that's what
+/// Mattermost expects even for a bad token or an unknown building — we just
+/// switch to an `ephemeral` response so only the invoking user sees it.
+async fn post_lunch_command(
+    State(state): State<AppState>,
+    Form(command): Form<MattermostSlashCommandRequest>,
+) -> Json<MattermostCommandResponse> {
+    if state.mattermost_token.is_empty() || command.token != state.mattermost_token {
+        return Json(MattermostCommandResponse::ephemeral(Markdown(
+            "Invalid Mattermost token.".to_owned(),
+        )));
+    }
+
+    let Some(building) = resolve_building(&command.text) else {
+        return Json(MattermostCommandResponse::ephemeral(Markdown(
+            USAGE.to_owned(),
+        )));
+    };
+
+    let today = Utc::now().date_naive();
+
+    match lunch::get_lunch(state.provider.as_ref(), building, today).await {
+        Ok(markdown_lunch) => Json(MattermostCommandResponse::in_channel(markdown_lunch)),
+        Err(_) => Json(MattermostCommandResponse::ephemeral(Markdown(format!(
+            "Could not fetch lunch for {}, please try again later.",
+            command.text.trim()
+        )))),
+    }
+}
+
+fn resolve_building(text: &str) -> Option<Building> {
+    text.split_whitespace().next()?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use async_trait::async_trait;
+    use axum_test::TestServer;
+    use chrono::NaiveDate;
+
+    use crate::lunch::Lunch;
+
+    use super::*;
+
+    struct FixtureProvider;
+
+    #[async_trait]
+    impl LunchProvider for FixtureProvider {
+        async fn fetch(&self, _building: Building, _date: NaiveDate) -> anyhow::Result<Lunch> {
+            Ok(Lunch::new(vec![(
+                "Varm ret".to_owned(),
+                "Frikadeller".to_owned(),
+            )]))
+        }
+    }
+
+    struct FailingProvider;
+
+    #[async_trait]
+    impl LunchProvider for FailingProvider {
+        async fn fetch(&self, _building: Building, _date: NaiveDate) -> anyhow::Result<Lunch> {
+            anyhow::bail!("scrape failed")
+        }
+    }
+
+    #[tokio::test]
+    async fn get_lunch_returns_in_channel_markdown() {
+        let state = AppState::from_provider(Arc::new(FixtureProvider), "s3cr3t");
+        let server = TestServer::new(App::router(state)).expect("failed to start test server");
+
+        let response = server.get("/api/aastvej/lunch").await;
+
+        response.assert_status_ok();
+        assert!(response.text().contains("Frikadeller"));
+    }
+
+    #[tokio::test]
+    async fn get_lunch_week_returns_in_channel_markdown_for_every_weekday() {
+        let state = AppState::from_provider(Arc::new(FixtureProvider), "s3cr3t");
+        let server = TestServer::new(App::router(state)).expect("failed to start test server");
+
+        let response = server.get("/api/aastvej/lunch/week").await;
+
+        response.assert_status_ok();
+        let body = response.text();
+        assert!(body.contains("Monday"));
+        assert!(body.contains("Friday"));
+        assert!(body.contains("Frikadeller"));
+    }
+
+    #[tokio::test]
+    async fn post_lunch_command_rejects_unknown_building_with_usage() {
+        let state = AppState::from_provider(Arc::new(FixtureProvider), "s3cr3t");
+        let server = TestServer::new(App::router(state)).expect("failed to start test server");
+
+        let response = server
+            .post("/api/lunch")
+            .form(&[("token", "s3cr3t"), ("text", "not-a-building")])
+            .await;
+
+        response.assert_status_ok();
+        assert!(response.text().contains("Usage"));
+    }
+
+    #[tokio::test]
+    async fn get_lunch_for_platform_renders_slack_shape() {
+        let state = AppState::from_provider(Arc::new(FixtureProvider), "s3cr3t");
+        let server = TestServer::new(App::router(state)).expect("failed to start test server");
+
+        let response = server.get("/api/slack/aastvej/lunch").await;
+
+        response.assert_status_ok();
+        let body = response.text();
+        assert!(body.contains("\"mrkdwn\":true"));
+        assert!(body.contains("Frikadeller"));
+    }
+
+    #[tokio::test]
+    async fn get_lunch_for_platform_renders_ephemeral_on_provider_error() {
+        let state = AppState::from_provider(Arc::new(FailingProvider), "s3cr3t");
+        let server = TestServer::new(App::router(state)).expect("failed to start test server");
+
+        let response = server.get("/api/slack/aastvej/lunch").await;
+
+        response.assert_status_ok();
+        let body = response.text();
+        assert!(body.contains("\"ephemeral\""));
+        assert!(body.contains("please try again"));
+    }
+
+    #[tokio::test]
+    async fn post_lunch_command_rejects_bad_token() {
+        let state = AppState::from_provider(Arc::new(FixtureProvider), "s3cr3t");
+        let server = TestServer::new(App::router(state)).expect("failed to start test server");
+
+        let response = server
+            .post("/api/lunch")
+            .form(&[("token", "wrong"), ("text", "aastvej")])
+            .await;
+
+        response.assert_status_ok();
+        assert!(response.text().contains("Invalid Mattermost token"));
+    }
+
+    #[tokio::test]
+    async fn post_lunch_command_fails_closed_when_token_unconfigured() {
+        let state = AppState::from_provider(Arc::new(FixtureProvider), "");
+        let server = TestServer::new(App::router(state)).expect("failed to start test server");
+
+        let response = server
+            .post("/api/lunch")
+            .form(&[("token", ""), ("text", "aastvej")])
+            .await;
+
+        response.assert_status_ok();
+        assert!(response.text().contains("Invalid Mattermost token"));
+    }
+}
@@ -0,0 +1,145 @@
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::mattermost::MattermostCommandResponse;
+use crate::Markdown;
+
+/// Which chat system a slash/interaction command came from, selected by the
+/// `{platform}` path segment.
+#[derive(
+    strum_macros::Display, strum_macros::EnumString, Debug, Clone, Copy, ToSchema, Deserialize,
+)]
+#[strum(serialize_all = "kebab-case")]
+#[serde(rename_all = "kebab-case")]
+pub(crate) enum ChatPlatform {
+    Mattermost,
+    Slack,
+    Discord,
+}
+
+/// A rendered chat response in whichever JSON shape its platform expects.
+/// The scraping and Markdown rendering stays platform-agnostic; this enum and
+/// the per-platform response types it wraps are the only code that knows
+/// about Mattermost's `response_type`, Slack's `mrkdwn`, or Discord's
+/// `type`/`data.content` envelope.
+pub(crate) enum ChatResponse {
+    Mattermost(MattermostCommandResponse),
+    Slack(SlackCommandResponse),
+    Discord(DiscordInteractionResponse),
+}
+
+impl ChatResponse {
+    pub(crate) fn in_channel(platform: ChatPlatform, markdown: Markdown) -> Self {
+        match platform {
+            ChatPlatform::Mattermost => {
+                Self::Mattermost(MattermostCommandResponse::in_channel(markdown))
+            }
+            ChatPlatform::Slack => Self::Slack(SlackCommandResponse::in_channel(markdown)),
+            ChatPlatform::Discord => {
+                Self::Discord(DiscordInteractionResponse::in_channel(markdown))
+            }
+        }
+    }
+
+    pub(crate) fn ephemeral(platform: ChatPlatform, markdown: Markdown) -> Self {
+        match platform {
+            ChatPlatform::Mattermost => {
+                Self::Mattermost(MattermostCommandResponse::ephemeral(markdown))
+            }
+            ChatPlatform::Slack => Self::Slack(SlackCommandResponse::ephemeral(markdown)),
+            ChatPlatform::Discord => {
+                Self::Discord(DiscordInteractionResponse::ephemeral(markdown))
+            }
+        }
+    }
+}
+
+impl IntoResponse for ChatResponse {
+    fn into_response(self) -> Response {
+        match self {
+            Self::Mattermost(response) => Json(response).into_response(),
+            Self::Slack(response) => Json(response).into_response(),
+            Self::Discord(response) => Json(response).into_response(),
+        }
+    }
+}
+
+/// A Slack slash command response: `response_type` is `in_channel` or
+/// `ephemeral`, same as Mattermost, with `mrkdwn` set so Slack renders the
+/// Markdown headings instead of showing them literally.
+#[derive(Serialize)]
+#[serde(rename_all = "snake_case")]
+enum SlackResponseType {
+    InChannel,
+    Ephemeral,
+}
+
+#[derive(Serialize)]
+pub(crate) struct SlackCommandResponse {
+    text: String,
+    response_type: SlackResponseType,
+    mrkdwn: bool,
+}
+
+impl SlackCommandResponse {
+    fn in_channel(markdown: Markdown) -> Self {
+        Self {
+            text: markdown.0,
+            response_type: SlackResponseType::InChannel,
+            mrkdwn: true,
+        }
+    }
+
+    fn ephemeral(markdown: Markdown) -> Self {
+        Self {
+            text: markdown.0,
+            response_type: SlackResponseType::Ephemeral,
+            mrkdwn: true,
+        }
+    }
+}
+
+/// A Discord interaction response: `type: 4` (`CHANNEL_MESSAGE_WITH_SOURCE`)
+/// with the message body nested under `data`. Discord has no separate
+/// ephemeral response type; instead the message carries the ephemeral flag
+/// (`1 << 6`) in `data.flags`.
+const DISCORD_CHANNEL_MESSAGE_WITH_SOURCE: u8 = 4;
+const DISCORD_EPHEMERAL_FLAG: u32 = 1 << 6;
+
+#[derive(Serialize)]
+pub(crate) struct DiscordInteractionResponse {
+    #[serde(rename = "type")]
+    kind: u8,
+    data: DiscordInteractionData,
+}
+
+#[derive(Serialize)]
+struct DiscordInteractionData {
+    content: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    flags: Option<u32>,
+}
+
+impl DiscordInteractionResponse {
+    fn in_channel(markdown: Markdown) -> Self {
+        Self {
+            kind: DISCORD_CHANNEL_MESSAGE_WITH_SOURCE,
+            data: DiscordInteractionData {
+                content: markdown.0,
+                flags: None,
+            },
+        }
+    }
+
+    fn ephemeral(markdown: Markdown) -> Self {
+        Self {
+            kind: DISCORD_CHANNEL_MESSAGE_WITH_SOURCE,
+            data: DiscordInteractionData {
+                content: markdown.0,
+                flags: Some(DISCORD_EPHEMERAL_FLAG),
+            },
+        }
+    }
+}
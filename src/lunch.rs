@@ -1,110 +1,119 @@
-use scraper::{Html, Selector};
-use serde::Deserialize;
+use async_trait::async_trait;
+use chrono::{Datelike, Duration as ChronoDuration, NaiveDate};
+use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
 use crate::Markdown;
 
-#[derive(strum_macros::Display, Debug, Clone, Copy, ToSchema, Deserialize)]
+#[derive(
+    strum_macros::Display, strum_macros::EnumString, Debug, Clone, Copy, ToSchema, Deserialize,
+)]
 #[strum(serialize_all = "kebab-case")]
+#[serde(rename_all = "kebab-case")]
 pub enum Building {
     Aastvej,
     Multihuset,
     Havremarken,
     #[strum(serialize = "kloeverblomsten-kirkbi")]
+    #[serde(rename = "kloeverblomsten-kirkbi")]
     KIRKBI,
     Midtown,
     Kornmarken,
     #[strum(serialize = "kantine-oestergade")]
+    #[serde(rename = "kantine-oestergade")]
     Oestergade,
 }
 
-pub(crate) async fn get_lunch(building: Building) -> anyhow::Result<Markdown> {
-    let url = format!("https://lego.isscatering.dk/{}", building.to_string());
-    let response = reqwest::get(url).await?.text().await?;
-    let html = Html::parse_document(&response);
-    let lunch = scrape_lunch(&html);
-    let markdown = lunch_to_markdown(&lunch);
+/// A canteen backend capable of fetching a `Lunch` for a building on a given
+/// date. Implementations own their own fetching, parsing and caching
+/// strategy; callers only depend on this trait, so a second backend (e.g. a
+/// JSON-based kantinemeny endpoint) can be added without touching the Axum
+/// handlers.
+#[async_trait]
+pub(crate) trait LunchProvider: Send + Sync {
+    async fn fetch(&self, building: Building, date: NaiveDate) -> anyhow::Result<Lunch>;
+}
+
+pub(crate) async fn get_lunch(
+    provider: &dyn LunchProvider,
+    building: Building,
+    date: NaiveDate,
+) -> anyhow::Result<Markdown> {
+    let lunch = provider.fetch(building, date).await?;
 
-    Ok(markdown)
+    Ok(lunch_to_markdown(&lunch))
 }
 
-fn scrape_lunch(html: &Html) -> Lunch {
-    let varm_ret_selector =
-        Selector::parse("div.menu-row:nth-child(2) > div:nth-child(2)").unwrap();
-    let vegetar_selector = Selector::parse("div.menu-row:nth-child(4) > div:nth-child(2)").unwrap();
-    let salat_selector = Selector::parse("div.menu-row:nth-child(6) > div:nth-child(2)").unwrap();
-
-    let varm_ret = html
-        .select(&varm_ret_selector)
-        .next()
-        .unwrap()
-        .text()
-        .next()
-        .unwrap();
-
-    let vegetar = html
-        .select(&vegetar_selector)
-        .next()
-        .unwrap()
-        .text()
-        .next()
-        .unwrap();
-
-    let salat = html
-        .select(&salat_selector)
-        .next()
-        .unwrap()
-        .text()
-        .next()
-        .unwrap();
-
-    Lunch {
-        varm_ret: String::from(varm_ret.trim()),
-        vegetar: String::from(vegetar.trim()),
-        salat: String::from(salat.trim()),
+/// Fetches the full work week (Monday through Friday) containing `date` and
+/// renders one Markdown section per weekday.
+pub(crate) async fn get_lunch_week(
+    provider: &dyn LunchProvider,
+    building: Building,
+    date: NaiveDate,
+) -> anyhow::Result<Markdown> {
+    let monday = date - ChronoDuration::days(date.weekday().num_days_from_monday() as i64);
+
+    let mut week = Vec::with_capacity(5);
+    for offset in 0..5 {
+        let day = monday + ChronoDuration::days(offset);
+        let lunch = provider.fetch(building, day).await?;
+        week.push((day, lunch));
     }
+
+    Ok(week_to_markdown(&week))
 }
 
 fn lunch_to_markdown(lunch: &Lunch) -> Markdown {
     Markdown(
-        [
-            "##### Varm ret\n  ",
-            lunch.varm_ret.as_str(),
-            "\n",
-            "##### Vegetar\n  ",
-            lunch.vegetar.as_str(),
-            "\n",
-            "##### Salat\n  ",
-            lunch.salat.as_str(),
-        ]
-        .join(""),
+        lunch
+            .categories
+            .iter()
+            .map(|(category, dish)| format!("##### {}\n  {}", category, dish))
+            .collect::<Vec<_>>()
+            .join("\n"),
     )
 }
 
-#[derive(Debug, PartialEq)]
-struct Lunch {
-    varm_ret: String,
-    vegetar: String,
-    salat: String,
+fn week_to_markdown(week: &[(NaiveDate, Lunch)]) -> Markdown {
+    Markdown(
+        week.iter()
+            .map(|(date, lunch)| {
+                format!("#### {}\n{}", date.format("%A"), lunch_to_markdown(lunch).0)
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n"),
+    )
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct Lunch {
+    categories: Vec<(String, String)>,
+}
+
+impl Lunch {
+    pub(crate) fn new(categories: Vec<(String, String)>) -> Self {
+        Self { categories }
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.categories.is_empty()
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::fs;
-    use std::path::Path;
-
-    use scraper::Html;
+    use chrono::NaiveDate;
 
     use crate::lunch;
     use crate::lunch::Lunch;
 
     #[test]
     fn lunch_to_markdown() {
-        let lunch = Lunch {
-            varm_ret: "Luftbøffer".to_owned(),
-            vegetar: "Mælkebøtter".to_owned(),
-            salat: "Gulerod".to_owned(),
-        };
+        let lunch = Lunch::new(vec![
+            ("Varm ret".to_owned(), "Luftbøffer".to_owned()),
+            ("Vegetar".to_owned(), "Mælkebøtter".to_owned()),
+            ("Salat".to_owned(), "Gulerod".to_owned()),
+        ]);
 
         let markdown = lunch::lunch_to_markdown(&lunch);
         let expected =
@@ -113,20 +122,23 @@ mod tests {
     }
 
     #[test]
-    fn scrape_lunch() {
-        let path_to_html =
-            Path::new(env!("CARGO_MANIFEST_DIR")).join("resources/test/aastvej.html");
-        let html_string = fs::read_to_string(path_to_html).unwrap();
-        let html = Html::parse_document(&html_string);
-
-        let result = lunch::scrape_lunch(&html);
-
-        let expected = Lunch {
-            varm_ret: "Braiseret svinekæber med rodfrugter".to_owned(),
-            vegetar: "Gnocchi med ratatouille.".to_owned(),
-            salat: "Romaine salat med bagte blommer, hvedekerner, løg og salatost.".to_owned(),
-        };
-
-        assert_eq!(result, expected);
+    fn week_to_markdown_groups_each_day_under_its_weekday_heading() {
+        let monday = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let tuesday = NaiveDate::from_ymd_opt(2024, 1, 2).unwrap();
+
+        let week = vec![
+            (
+                monday,
+                Lunch::new(vec![("Varm ret".to_owned(), "Luftbøffer".to_owned())]),
+            ),
+            (
+                tuesday,
+                Lunch::new(vec![("Vegetar".to_owned(), "Mælkebøtter".to_owned())]),
+            ),
+        ];
+
+        let markdown = lunch::week_to_markdown(&week);
+        let expected = "#### Monday\n##### Varm ret\n  Luftbøffer\n\n#### Tuesday\n##### Vegetar\n  Mælkebøtter";
+        assert_eq!(expected.to_owned(), markdown.0)
     }
 }
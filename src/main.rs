@@ -1,34 +1,12 @@
-use axum::{Json, Router};
-use axum::extract::Path;
-use axum::http::StatusCode;
-use axum::response::Redirect;
-use axum::routing::get;
 use log::info;
-use tower_http::trace::{self, TraceLayer};
-use tracing::Level;
-use utoipa::OpenApi;
-use utoipa_rapidoc::RapiDoc;
 
-use lunch::Building;
-use mattermost::MattermostCommandResponse;
+use app::{App, AppState};
 
+mod app;
+mod chat;
 mod lunch;
 mod mattermost;
-
-
-#[derive(OpenApi)]
-#[openapi(
-    paths(
-        get_lunch,
-    ),
-    components(
-        schemas(lunch::Building)
-    ),
-    tags(
-        (name = "lunch", description = "Lunch")
-    )
-)]
-struct ApiDoc;
+mod provider;
 
 pub(crate) struct Markdown(String);
 
@@ -36,17 +14,7 @@ pub(crate) struct Markdown(String);
 async fn main() {
     tracing_subscriber::fmt::init();
 
-    let api = Router::new().route("/:building/lunch", get(get_lunch));
-
-    let app = Router::new()
-        .merge(RapiDoc::with_openapi("/api-docs/openapi.json", ApiDoc::openapi()).path("/rapidoc"))
-        .route("/", get(|| async { Redirect::permanent("/rapidoc") }))
-        .nest("/api", api)
-        .layer(
-            TraceLayer::new_for_http()
-                .make_span_with(trace::DefaultMakeSpan::new().level(Level::INFO))
-                .on_response(trace::DefaultOnResponse::new().level(Level::INFO)),
-        );
+    let app = App::router(AppState::from_env());
 
     info!("Listening on http://127.0.0.1:8080");
 
@@ -55,22 +23,3 @@ async fn main() {
         .await
         .unwrap();
 }
-
-#[utoipa::path(
-    get,
-    path = "/api/{building}/lunch",
-    params(
-        ("building" = Building, Path, description = "the building for which to get lunch")
-    ),
-    responses(
-        (status = 200, description = "Get lunch for specified building")
-    )
-)]
-async fn get_lunch(
-    Path(building): Path<Building>,
-) -> Result<Json<MattermostCommandResponse>, StatusCode> {
-    match lunch::get_lunch(building).await {
-        Ok(markdown_lunch) => Ok(Json(MattermostCommandResponse::in_channel(markdown_lunch))),
-        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
-    }
-}
\ No newline at end of file
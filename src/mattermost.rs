@@ -1,10 +1,19 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::Markdown;
 
+/// The url-encoded form body Mattermost POSTs when a user invokes a slash
+/// command. Mattermost sends a handful of other fields (`team_id`,
+/// `user_id`, `response_url`, ...) that we don't need yet and simply ignore.
+#[derive(Deserialize)]
+pub(crate) struct MattermostSlashCommandRequest {
+    pub(crate) token: String,
+    #[serde(default)]
+    pub(crate) text: String,
+}
+
 #[derive(Serialize)]
 #[serde(rename_all = "snake_case")]
-#[allow(dead_code)] // Ephemeral not used currently
 enum MattermostResponseType {
     InChannel,
     Ephemeral,
@@ -24,7 +33,6 @@ impl MattermostCommandResponse {
         }
     }
 
-    #[allow(dead_code)] // Ephemeral not used currently
     pub fn ephemeral(markdown: Markdown) -> Self {
         Self {
             text: markdown.0,
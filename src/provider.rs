@@ -0,0 +1,264 @@
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::{NaiveDate, Utc};
+use scraper::{ElementRef, Html, Selector};
+use serde::{Deserialize, Serialize};
+
+use crate::lunch::{Building, Lunch, LunchProvider};
+
+/// Default staleness window used when `CACHE_STALENESS_SECONDS` isn't set.
+pub(crate) const DEFAULT_CACHE_STALENESS_WINDOW: Duration = Duration::from_secs(60 * 60);
+
+/// Scrapes ISS Catering's per-building menu pages, the canteen backend used
+/// by LEGO's own buildings.
+pub(crate) struct IssCateringProvider {
+    client: reqwest::Client,
+    base_url: String,
+    cache_staleness_window: Duration,
+}
+
+impl IssCateringProvider {
+    pub(crate) fn new(
+        client: reqwest::Client,
+        base_url: String,
+        cache_staleness_window: Duration,
+    ) -> Self {
+        Self {
+            client,
+            base_url,
+            cache_staleness_window,
+        }
+    }
+
+    fn dated_url(&self, building: Building, date: NaiveDate) -> String {
+        format!(
+            "{}/{}?dato={}",
+            self.base_url,
+            building,
+            date.format("%Y-%m-%d")
+        )
+    }
+}
+
+#[async_trait]
+impl LunchProvider for IssCateringProvider {
+    async fn fetch(&self, building: Building, date: NaiveDate) -> anyhow::Result<Lunch> {
+        let cache_path = cache_path(building, date);
+
+        if let Some(path) = &cache_path {
+            if let Some(lunch) = read_cache(path, self.cache_staleness_window) {
+                return Ok(lunch);
+            }
+        }
+
+        let url = self.dated_url(building, date);
+        let response = self.client.get(url).send().await?.text().await?;
+        let html = Html::parse_document(&response);
+        let lunch = scrape_lunch(&html);
+
+        if lunch.is_empty() {
+            anyhow::bail!("found no menu rows for {} on {}", building, date);
+        }
+
+        if let Some(path) = &cache_path {
+            write_cache(path, &lunch);
+        }
+
+        Ok(lunch)
+    }
+}
+
+/// Path of the cache file for a given building and date, rooted under the
+/// OS cache directory. Returns `None` if the platform has no cache directory,
+/// in which case callers should fall straight through to the network.
+fn cache_path(building: Building, date: NaiveDate) -> Option<PathBuf> {
+    let mut path = dirs::cache_dir()?;
+    path.push("whats-for-lunch");
+    path.push(format!("{}-{}.json", building, date));
+    Some(path)
+}
+
+#[derive(Serialize, Deserialize)]
+struct CachedLunch {
+    timestamp: chrono::DateTime<Utc>,
+    lunch: Lunch,
+}
+
+/// Reads a cached `Lunch` from `path` if it exists, parses, and is still
+/// within `staleness_window`. Any missing or unparseable cache file is
+/// treated as a cache miss rather than an error.
+fn read_cache(path: &PathBuf, staleness_window: Duration) -> Option<Lunch> {
+    let contents = fs::read_to_string(path).ok()?;
+    let cached: CachedLunch = serde_json::from_str(&contents).ok()?;
+    let age = Utc::now().signed_duration_since(cached.timestamp).to_std().ok()?;
+
+    if age < staleness_window {
+        Some(cached.lunch)
+    } else {
+        None
+    }
+}
+
+/// Best-effort write of a freshly scraped `Lunch` to `path`. Failures to
+/// create the cache directory or serialize are swallowed since the cache is
+/// purely an optimization and must never fail the request.
+fn write_cache(path: &PathBuf, lunch: &Lunch) {
+    if let Some(parent) = path.parent() {
+        let _ = fs::create_dir_all(parent);
+    }
+
+    let cached = CachedLunch {
+        timestamp: Utc::now(),
+        lunch: lunch.clone(),
+    };
+
+    if let Ok(json) = serde_json::to_string(&cached) {
+        let _ = fs::write(path, json);
+    }
+}
+
+/// Scrapes every `div.menu-row` on the page into an ordered list of
+/// `(category, dish)` pairs, reading the category label from the row's first
+/// direct child and the dish text from its second. We deliberately walk
+/// `row.children()` rather than a `"div"` descendant selector, since a
+/// descendant selector would also match divs nested further down (e.g. an
+/// icon wrapper inside the category cell) and pick up the wrong text. Rows
+/// missing either child, or with blank text, are skipped rather than
+/// panicking, so this keeps working whether a building's page has three
+/// categories or ten.
+fn scrape_lunch(html: &Html) -> Lunch {
+    let row_selector = Selector::parse("div.menu-row").unwrap();
+
+    let categories = html
+        .select(&row_selector)
+        .filter_map(|row| {
+            let mut cells = row.children().filter_map(ElementRef::wrap);
+            let category = direct_text(cells.next()?);
+            let dish = direct_text(cells.next()?);
+
+            if category.is_empty() || dish.is_empty() {
+                return None;
+            }
+
+            Some((category, dish))
+        })
+        .collect();
+
+    Lunch::new(categories)
+}
+
+/// Concatenates only the direct text-node children of `element`, trimmed,
+/// ignoring text nested inside child elements (e.g. an icon wrapper span).
+/// `ElementRef::text()` would otherwise walk every descendant text node and
+/// could return the icon's text instead of the cell's actual label.
+fn direct_text(element: ElementRef) -> String {
+    element
+        .children()
+        .filter_map(|node| node.value().as_text())
+        .map(|text| &**text)
+        .collect::<String>()
+        .trim()
+        .to_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::Path;
+    use std::time::Duration;
+
+    use scraper::Html;
+
+    use crate::lunch::Lunch;
+    use crate::provider;
+
+    #[test]
+    fn scrape_lunch() {
+        let path_to_html =
+            Path::new(env!("CARGO_MANIFEST_DIR")).join("resources/test/aastvej.html");
+        let html_string = fs::read_to_string(path_to_html).unwrap();
+        let html = Html::parse_document(&html_string);
+
+        let result = provider::scrape_lunch(&html);
+
+        let expected = Lunch::new(vec![
+            (
+                "Varm ret".to_owned(),
+                "Braiseret svinekæber med rodfrugter".to_owned(),
+            ),
+            ("Vegetar".to_owned(), "Gnocchi med ratatouille.".to_owned()),
+            (
+                "Salat".to_owned(),
+                "Romaine salat med bagte blommer, hvedekerner, løg og salatost.".to_owned(),
+            ),
+        ]);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn scrape_lunch_ignores_nested_divs() {
+        let html = Html::parse_document(
+            r#"<div class="menu-row"><div><span><div>ICON</div></span>Varm ret</div><div>Luftbøffer</div></div>"#,
+        );
+
+        let result = provider::scrape_lunch(&html);
+
+        let expected = Lunch::new(vec![("Varm ret".to_owned(), "Luftbøffer".to_owned())]);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn scrape_lunch_skips_empty_rows() {
+        let html = Html::parse_document(
+            r#"<div class="menu-row"><div>Varm ret</div><div>Luftbøffer</div></div>
+               <div class="menu-row"><div>Tom</div><div></div></div>"#,
+        );
+
+        let result = provider::scrape_lunch(&html);
+
+        let expected = Lunch::new(vec![("Varm ret".to_owned(), "Luftbøffer".to_owned())]);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn write_cache_then_read_cache_round_trips_within_window() {
+        let path = std::env::temp_dir().join("whats-for-lunch-test-round-trip.json");
+        let lunch = Lunch::new(vec![("Varm ret".to_owned(), "Luftbøffer".to_owned())]);
+
+        provider::write_cache(&path, &lunch);
+        let result = provider::read_cache(&path, Duration::from_secs(60 * 60));
+
+        fs::remove_file(&path).ok();
+
+        assert_eq!(result, Some(lunch));
+    }
+
+    #[test]
+    fn read_cache_treats_an_expired_entry_as_a_miss() {
+        let path = std::env::temp_dir().join("whats-for-lunch-test-staleness.json");
+        let lunch = Lunch::new(vec![("Varm ret".to_owned(), "Luftbøffer".to_owned())]);
+
+        provider::write_cache(&path, &lunch);
+        let result = provider::read_cache(&path, Duration::ZERO);
+
+        fs::remove_file(&path).ok();
+
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn read_cache_treats_a_missing_file_as_a_miss() {
+        let path = std::env::temp_dir().join("whats-for-lunch-test-missing.json");
+        fs::remove_file(&path).ok();
+
+        let result = provider::read_cache(&path, Duration::from_secs(60 * 60));
+
+        assert_eq!(result, None);
+    }
+}